@@ -0,0 +1,139 @@
+use std::fs;
+use std::path::PathBuf;
+
+use once_cell::sync::Lazy;
+use ratatui::style::Color;
+use serde::Deserialize;
+
+/// The active color palette for the TUI, built by overlaying the `[theme]` table of the user's
+/// config file onto `ColorTheme::default()`. Falls back to the default untouched if the config
+/// file is missing, unreadable, or has no `[theme]` table.
+pub static CURRENT_THEME: Lazy<ColorTheme> = Lazy::new(|| ColorTheme::from_partial(read_partial_theme()));
+
+/// The config file as it pertains to theming; the rest of the user's config is out of scope
+/// here and simply ignored by `#[serde(default)]` plus `toml`'s tolerance of unknown tables.
+#[derive(Debug, Default, Deserialize)]
+struct ThemeConfigFile {
+    #[serde(default)]
+    theme: PartialColorTheme,
+}
+
+fn config_file_path() -> PathBuf {
+    dirs::config_dir().unwrap_or_default().join("manga-tui").join("config.toml")
+}
+
+fn read_partial_theme() -> PartialColorTheme {
+    let Ok(contents) = fs::read_to_string(config_file_path()) else {
+        return PartialColorTheme::default();
+    };
+
+    toml::from_str::<ThemeConfigFile>(&contents).unwrap_or_default().theme
+}
+
+/// A partial theme as it appears in the user's config file: every field optional, so a user
+/// only has to override the colors they care about.
+#[derive(Debug, Default, Deserialize)]
+pub struct PartialColorTheme {
+    pub text: Option<Color>,
+    pub selected: Option<Color>,
+    pub selected_text: Option<Color>,
+    pub bookmark_bg: Option<Color>,
+    pub bookmark_fg: Option<Color>,
+    pub loading: Option<Color>,
+    pub warning: Option<Color>,
+    pub sleeping: Option<Color>,
+    pub read: Option<Color>,
+}
+
+/// Central color palette for the whole TUI. Widgets should read colors from here instead of
+/// hardcoding `Color`/`Style` literals, so a user can ship a light/dark or high-contrast palette
+/// through their config file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ColorTheme {
+    pub text: Color,
+    pub selected: Color,
+    pub selected_text: Color,
+    pub bookmark_bg: Color,
+    pub bookmark_fg: Color,
+    pub loading: Color,
+    pub warning: Color,
+    pub sleeping: Color,
+    /// Subtle foreground for rows the user has already looked at, distinct from the bookmark
+    /// color so the resume point stays the only "strong" highlight.
+    pub read: Color,
+}
+
+impl Default for ColorTheme {
+    fn default() -> Self {
+        Self {
+            text: Color::Reset,
+            selected: Color::DarkGray,
+            selected_text: Color::White,
+            bookmark_bg: Color::Green,
+            bookmark_fg: Color::Black,
+            loading: Color::Yellow,
+            warning: Color::Red,
+            sleeping: Color::Reset,
+            read: Color::Gray,
+        }
+    }
+}
+
+impl ColorTheme {
+    /// Overlays a `PartialColorTheme` (as read from the user's config file) onto `Default`,
+    /// keeping the default for any field the user left unset.
+    pub fn from_partial(partial: PartialColorTheme) -> Self {
+        let default = Self::default();
+        Self {
+            text: partial.text.unwrap_or(default.text),
+            selected: partial.selected.unwrap_or(default.selected),
+            selected_text: partial.selected_text.unwrap_or(default.selected_text),
+            bookmark_bg: partial.bookmark_bg.unwrap_or(default.bookmark_bg),
+            bookmark_fg: partial.bookmark_fg.unwrap_or(default.bookmark_fg),
+            loading: partial.loading.unwrap_or(default.loading),
+            warning: partial.warning.unwrap_or(default.warning),
+            sleeping: partial.sleeping.unwrap_or(default.sleeping),
+            read: partial.read.unwrap_or(default.read),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn it_overlays_partial_theme_onto_default() {
+        let partial = PartialColorTheme { warning: Some(Color::Magenta), ..Default::default() };
+
+        let theme = ColorTheme::from_partial(partial);
+
+        assert_eq!(Color::Magenta, theme.warning);
+        assert_eq!(ColorTheme::default().text, theme.text);
+    }
+
+    #[test]
+    fn it_parses_a_theme_table_out_of_the_user_config_file() {
+        let config = toml::from_str::<ThemeConfigFile>(
+            r#"
+            [theme]
+            warning = "Magenta"
+            "#,
+        )
+        .unwrap();
+
+        let theme = ColorTheme::from_partial(config.theme);
+
+        assert_eq!(Color::Magenta, theme.warning);
+        assert_eq!(ColorTheme::default().bookmark_bg, theme.bookmark_bg);
+    }
+
+    #[test]
+    fn it_falls_back_to_the_default_theme_when_the_config_has_no_theme_table() {
+        let config = toml::from_str::<ThemeConfigFile>("").unwrap();
+
+        assert_eq!(ColorTheme::default(), ColorTheme::from_partial(config.theme));
+    }
+}
@@ -1,14 +1,13 @@
-use once_cell::sync::Lazy;
+use std::collections::HashSet;
+
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Style, Stylize};
-use ratatui::widgets::{Block, Paragraph, StatefulWidget, Widget, Wrap};
+use ratatui::style::{Style, Stylize};
+use ratatui::widgets::{Block, Padding, Paragraph, StatefulWidget, Widget, Wrap};
 use throbber_widgets_tui::{Throbber, ThrobberState};
 use tui_widget_list::PreRender;
 
-use crate::global::CURRENT_LIST_ITEM_STYLE;
-
-pub static STYLE_PAGE_BOOKMARKED: Lazy<Style> = Lazy::new(|| Style::new().on_green().black());
+use crate::global::{ColorTheme, CURRENT_THEME};
 
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub enum PageItemState {
@@ -18,12 +17,122 @@ pub enum PageItemState {
     Waiting,
 }
 
+impl PageItemState {
+    fn label(&self) -> &'static str {
+        match self {
+            PageItemState::Loading => "Loading",
+            PageItemState::FinishedLoad => "Loaded",
+            PageItemState::FailedLoad => "Failed",
+            PageItemState::Waiting => "Waiting",
+        }
+    }
+}
+
+/// How densely a `PagesItem` is rendered. `Compact` packs one row per page with no decoration,
+/// `Detailed` gives each page a bordered block with its load state spelled out, at the cost of
+/// showing fewer pages at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageRowView {
+    Compact,
+    #[default]
+    Detailed,
+}
+
+impl PageRowView {
+    fn height(self) -> u16 {
+        match self {
+            PageRowView::Compact => 1,
+            // 2 border lines (top/bottom of the block) + the state label and indicator rows
+            // `PageBody` renders into the inner area.
+            PageRowView::Detailed => 4,
+        }
+    }
+
+    pub fn toggle(self) -> Self {
+        match self {
+            PageRowView::Compact => PageRowView::Detailed,
+            PageRowView::Detailed => PageRowView::Compact,
+        }
+    }
+}
+
+/// Wraps a child widget in a `Block` (with `Padding` and a base `Style`), rendering the block
+/// first and then the child into the block's inner area. Used to give list rows borders/padding
+/// without every widget having to compute its own inner area.
+struct ItemContainer<W> {
+    child: W,
+    block: Block<'static>,
+    padding: Padding,
+    style: Style,
+}
+
+impl<W: Widget> Widget for ItemContainer<W> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let block = self.block.padding(self.padding).style(self.style);
+        let inner = block.inner(area);
+
+        block.render(area, buf);
+        self.child.render(inner, buf);
+    }
+}
+
+/// The body of a `Detailed` `PagesItem`: the load state label on one line and the
+/// throbber/warning/sleep indicator on the next.
+struct PageBody<'a> {
+    state: PageItemState,
+    loading_state: &'a mut ThrobberState,
+    theme: &'static ColorTheme,
+}
+
+impl Widget for PageBody<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer)
+    where
+        Self: Sized,
+    {
+        let rows = Layout::vertical([Constraint::Length(1), Constraint::Length(1)]);
+        let [label_area, indicator_area] = rows.areas(area);
+
+        Paragraph::new(self.state.label()).wrap(Wrap { trim: true }).render(label_area, buf);
+
+        match self.state {
+            PageItemState::Loading => {
+                let loader = Throbber::default()
+                    .style(Style::default().fg(self.theme.loading))
+                    .throbber_set(throbber_widgets_tui::BRAILLE_SIX)
+                    .use_type(throbber_widgets_tui::WhichUse::Spin);
+
+                StatefulWidget::render(loader, indicator_area, buf, self.loading_state);
+            },
+            PageItemState::FailedLoad => {
+                Paragraph::new("⚠").wrap(Wrap { trim: true }).fg(self.theme.warning).bold().render(indicator_area, buf);
+            },
+            PageItemState::Waiting => {
+                Paragraph::new("💤").wrap(Wrap { trim: true }).fg(self.theme.sleeping).bold().render(indicator_area, buf);
+            },
+            PageItemState::FinishedLoad => {},
+        }
+    }
+}
+
+/// Base delay (in ticks) before the first retry of a failed page; doubled per subsequent
+/// attempt, capped at `MAX_RETRY_TICKS`.
+const BASE_RETRY_TICKS: u32 = 2;
+const MAX_RETRY_TICKS: u32 = 32;
+
 #[derive(Clone)]
 pub struct PagesItem {
     pub number: usize,
     pub state: PageItemState,
     pub loading_state: ThrobberState,
     pub style: Style,
+    pub theme: &'static ColorTheme,
+    pub view: PageRowView,
+    pub is_focused: bool,
+    pub attempt: u32,
+    retry_ticks_remaining: u32,
 }
 
 impl Widget for PagesItem {
@@ -31,12 +140,19 @@ impl Widget for PagesItem {
     where
         Self: Sized,
     {
+        match self.view {
+            PageRowView::Compact => self.render_compact(area, buf),
+            PageRowView::Detailed => self.render_detailed(area, buf),
+        }
+    }
+}
+
+impl PagesItem {
+    fn render_compact(&mut self, area: Rect, buf: &mut Buffer) {
         let layout = Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]);
         let [chapter_number_area, loader_area] = layout.areas(area);
 
-        let block_style = self.style;
-
-        Block::default().style(block_style).render(area, buf);
+        Block::default().style(self.style).render(area, buf);
 
         let page = Paragraph::new(format!("Page {}", self.number)).wrap(Wrap { trim: true });
 
@@ -44,7 +160,7 @@ impl Widget for PagesItem {
             PageItemState::Loading => {
                 let loader = Throbber::default()
                     .label("Loading")
-                    .style(Style::default().fg(Color::Yellow))
+                    .style(Style::default().fg(self.theme.loading))
                     .throbber_set(throbber_widgets_tui::BRAILLE_SIX)
                     .use_type(throbber_widgets_tui::WhichUse::Spin);
 
@@ -57,22 +173,47 @@ impl Widget for PagesItem {
             },
             PageItemState::FailedLoad => {
                 page.render(chapter_number_area, buf);
-                Paragraph::new("⚠").wrap(Wrap { trim: true }).red().bold().render(loader_area, buf);
+                Paragraph::new("⚠")
+                    .wrap(Wrap { trim: true })
+                    .fg(self.theme.warning)
+                    .bold()
+                    .render(loader_area, buf);
             },
             PageItemState::Waiting => {
                 page.render(chapter_number_area, buf);
-                Paragraph::new("💤").wrap(Wrap { trim: true }).bold().render(loader_area, buf);
+                Paragraph::new("💤")
+                    .wrap(Wrap { trim: true })
+                    .fg(self.theme.sleeping)
+                    .bold()
+                    .render(loader_area, buf);
             },
         }
     }
+
+    /// The highlight style for the currently selected row: the full `selected`/`selected_text`
+    /// pair while this item's region has focus, or just a dimmed foreground while it doesn't.
+    fn selected_style(&self) -> Style {
+        if self.is_focused {
+            Style::new().bg(self.theme.selected).fg(self.theme.selected_text)
+        } else {
+            Style::new().fg(self.theme.selected)
+        }
+    }
+
+    fn render_detailed(&mut self, area: Rect, buf: &mut Buffer) {
+        let block = Block::bordered().title(format!("Page {}", self.number));
+        let body = PageBody { state: self.state.clone(), loading_state: &mut self.loading_state, theme: self.theme };
+
+        ItemContainer { child: body, block, padding: Padding::horizontal(1), style: self.style }.render(area, buf);
+    }
 }
 
 impl PreRender for PagesItem {
     fn pre_render(&mut self, context: &tui_widget_list::PreRenderContext) -> u16 {
         if context.is_selected {
-            self.style = *CURRENT_LIST_ITEM_STYLE;
+            self.style = self.selected_style();
         }
-        2
+        self.view.height()
     }
 }
 
@@ -83,24 +224,70 @@ impl PagesItem {
             state: PageItemState::Waiting,
             loading_state: ThrobberState::default(),
             style: Style::default(),
+            theme: &CURRENT_THEME,
+            view: PageRowView::default(),
+            is_focused: true,
+            attempt: 0,
+            retry_ticks_remaining: 0,
         }
     }
 
     pub fn on_tick(&mut self) {
-        if self.state == PageItemState::Loading {
-            self.loading_state.calc_next();
+        match self.state {
+            PageItemState::Loading => self.loading_state.calc_next(),
+            PageItemState::FailedLoad if self.retry_ticks_remaining > 0 => {
+                self.retry_ticks_remaining -= 1;
+                if self.retry_ticks_remaining == 0 {
+                    self.state = PageItemState::Waiting;
+                }
+            },
+            _ => {},
         }
     }
+
+    /// Promotes this page from `Waiting` into `Loading`.
+    pub fn mark_loading(&mut self) {
+        self.state = PageItemState::Loading;
+    }
+
+    /// Marks this page as successfully loaded and resets its retry bookkeeping.
+    pub fn mark_finished(&mut self) {
+        self.state = PageItemState::FinishedLoad;
+        self.attempt = 0;
+    }
+
+    /// Marks this page as failed and schedules a retry after an exponentially increasing
+    /// backoff (`BASE_RETRY_TICKS * 2^attempt`, capped at `MAX_RETRY_TICKS`); `on_tick` counts
+    /// the delay down and returns the page to `Waiting` once it elapses.
+    pub fn mark_failed(&mut self) {
+        let delay = BASE_RETRY_TICKS.saturating_mul(1 << self.attempt.min(8)).min(MAX_RETRY_TICKS);
+        self.retry_ticks_remaining = delay;
+        self.attempt = self.attempt.saturating_add(1);
+        self.state = PageItemState::FailedLoad;
+    }
 }
 
-#[derive(Clone, Default)]
+/// How many pages either side of the selected page `advance_window` keeps promoted.
+const DEFAULT_PREFETCH_WINDOW: usize = 3;
+/// How many pages may be `Loading` at once.
+const DEFAULT_MAX_CONCURRENT_LOADS: usize = 3;
+
+#[derive(Clone)]
 pub struct PagesList {
     pub pages: Vec<PagesItem>,
+    pub window: usize,
+    pub max_concurrent_loads: usize,
+}
+
+impl Default for PagesList {
+    fn default() -> Self {
+        Self { pages: Vec::new(), window: DEFAULT_PREFETCH_WINDOW, max_concurrent_loads: DEFAULT_MAX_CONCURRENT_LOADS }
+    }
 }
 
 impl PagesList {
     pub fn new(pages: Vec<PagesItem>) -> Self {
-        Self { pages }
+        Self { pages, ..Self::default() }
     }
 
     pub fn on_tick(&mut self) {
@@ -109,35 +296,139 @@ impl PagesList {
         }
     }
 
-    fn reset_style(&mut self) {
-        self.pages.iter_mut().for_each(|page| page.style = Style::default())
+    fn in_flight(&self) -> usize {
+        self.pages.iter().filter(|page| page.state == PageItemState::Loading).count()
     }
 
-    pub fn highlight_page_as_bookmarked(&mut self, page_index: usize) {
-        self.reset_style();
+    /// Promotes up to `max_concurrent_loads - in_flight` `Waiting` pages within `window` of
+    /// `selected` into `Loading`, nearest pages first.
+    pub fn advance_window(&mut self, selected: usize) {
+        if self.pages.is_empty() {
+            return;
+        }
+
+        let mut budget = self.max_concurrent_loads.saturating_sub(self.in_flight());
+        if budget == 0 {
+            return;
+        }
+
+        let lower = selected.saturating_sub(self.window);
+        let upper = (selected + self.window).min(self.pages.len() - 1);
+
+        let mut candidates: Vec<usize> = (lower..=upper).collect();
+        candidates.sort_by_key(|index| index.abs_diff(selected));
+
+        for index in candidates {
+            if budget == 0 {
+                break;
+            }
+            if let Some(page) = self.pages.get_mut(index) {
+                if page.state == PageItemState::Waiting {
+                    page.mark_loading();
+                    budget -= 1;
+                }
+            }
+        }
+    }
+
+    /// Reports that `index` finished loading successfully.
+    pub fn mark_finished(&mut self, index: usize) {
+        if let Some(page) = self.pages.get_mut(index) {
+            page.mark_finished();
+        }
+    }
+
+    /// Reports that `index` failed to load, scheduling a backoff retry.
+    pub fn mark_failed(&mut self, index: usize) {
+        if let Some(page) = self.pages.get_mut(index) {
+            page.mark_failed();
+        }
+    }
+
+    /// Indices of pages that have recovered from a failure (backoff elapsed, now `Waiting`
+    /// again) and are ready to be reconsidered by `advance_window`.
+    pub fn poll_retries(&self) -> Vec<usize> {
+        self.pages
+            .iter()
+            .enumerate()
+            .filter(|(_, page)| page.state == PageItemState::Waiting && page.attempt > 0)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Resets every row's base style, giving already-read pages a subtle `theme.read` tint so
+    /// the bookmark color stays reserved for the resume point.
+    fn reset_style(&mut self, read_pages: &HashSet<usize>) {
+        for (index, page) in self.pages.iter_mut().enumerate() {
+            page.style = if read_pages.contains(&index) {
+                Style::new().fg(page.theme.read)
+            } else {
+                Style::new().fg(page.theme.text)
+            };
+        }
+    }
+
+    pub fn highlight_page_as_bookmarked(&mut self, page_index: usize, read_pages: &HashSet<usize>) {
+        self.reset_style(read_pages);
         if let Some(page) = self.pages.get_mut(page_index) {
-            page.style = *STYLE_PAGE_BOOKMARKED;
+            page.style = Style::new().bg(page.theme.bookmark_bg).fg(page.theme.bookmark_fg);
         }
     }
 }
 
-#[derive(Debug, Default)]
+/// Aggregate read progress for a chapter, exposed so the reader can display e.g. "12/40 read".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ReadProgress {
+    pub read: usize,
+    pub total: usize,
+}
+
+#[derive(Debug)]
 pub struct PagesListState {
     pub list_state: tui_widget_list::ListState,
     pub page_bookmarked: Option<usize>,
+    pub view: PageRowView,
+    pub is_focused: bool,
+    pub read_pages: HashSet<usize>,
 }
 
-impl PagesListState {
-    pub fn new(page_bookmarked: Option<usize>) -> Self {
+impl Default for PagesListState {
+    fn default() -> Self {
         Self {
             list_state: tui_widget_list::ListState::default(),
-            page_bookmarked,
+            page_bookmarked: None,
+            view: PageRowView::default(),
+            is_focused: true,
+            read_pages: HashSet::new(),
         }
     }
+}
+
+impl PagesListState {
+    pub fn new(page_bookmarked: Option<usize>) -> Self {
+        Self { page_bookmarked, ..Self::default() }
+    }
 
     pub fn set_page_bookmarked(&mut self, page_bookmarked: usize) {
         self.page_bookmarked = Some(page_bookmarked);
     }
+
+    /// Cycles between `Compact` and `Detailed` row rendering; `PagesList` picks up the new mode
+    /// on its next render. No key is bound to this here.
+    pub fn cycle_view(&mut self) {
+        self.view = self.view.toggle();
+    }
+
+    /// Marks a page as seen. Distinct from `page_bookmarked`, which tracks only the single
+    /// resume point. Pages are not marked automatically: a page counts as read only once this
+    /// has been called for it.
+    pub fn mark_read(&mut self, index: usize) {
+        self.read_pages.insert(index);
+    }
+
+    pub fn progress(&self, total: usize) -> ReadProgress {
+        ReadProgress { read: self.read_pages.len(), total }
+    }
 }
 
 impl StatefulWidget for PagesList {
@@ -148,7 +439,14 @@ impl StatefulWidget for PagesList {
             if state.list_state.selected.is_none() {
                 state.list_state.select(Some(page));
             }
-            self.highlight_page_as_bookmarked(page);
+            self.highlight_page_as_bookmarked(page, &state.read_pages);
+        } else {
+            self.reset_style(&state.read_pages);
+        }
+
+        for page in self.pages.iter_mut() {
+            page.view = state.view;
+            page.is_focused = state.is_focused;
         }
 
         let items = tui_widget_list::List::new(self.pages);
@@ -157,6 +455,51 @@ impl StatefulWidget for PagesList {
     }
 }
 
+/// The focusable regions of the reader screen, in the order `ReaderFocus` cycles through them.
+/// This only names the regions; no input is routed by focus in this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Focus {
+    #[default]
+    PagesList,
+    ImageViewport,
+    ChapterInfo,
+}
+
+const FOCUS_ORDER: [Focus; 3] = [Focus::PagesList, Focus::ImageViewport, Focus::ChapterInfo];
+
+/// Owns which region of the reader screen currently has focus and cycles it with
+/// `next`/`previous`. It does not read key events itself.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReaderFocus {
+    current: Focus,
+}
+
+impl ReaderFocus {
+    pub fn current(&self) -> Focus {
+        self.current
+    }
+
+    pub fn is_focused(&self, focus: Focus) -> bool {
+        self.current == focus
+    }
+
+    fn index(&self) -> usize {
+        FOCUS_ORDER.iter().position(|focus| *focus == self.current).expect("current focus is always in FOCUS_ORDER")
+    }
+
+    /// Cycles focus forward through `FOCUS_ORDER`, wrapping from the last region to the first.
+    pub fn next(&mut self) {
+        let next_index = (self.index() + 1) % FOCUS_ORDER.len();
+        self.current = FOCUS_ORDER[next_index];
+    }
+
+    /// Cycles focus backward through `FOCUS_ORDER`, wrapping from the first region to the last.
+    pub fn previous(&mut self) {
+        let previous_index = (self.index() + FOCUS_ORDER.len() - 1) % FOCUS_ORDER.len();
+        self.current = FOCUS_ORDER[previous_index];
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pretty_assertions::assert_eq;
@@ -167,27 +510,194 @@ mod tests {
     fn it_highlights_page_item_which_is_bookmarked() {
         let mut page_list = PagesList::new(vec![PagesItem::new(0), PagesItem::new(1)]);
 
-        page_list.highlight_page_as_bookmarked(1);
+        page_list.highlight_page_as_bookmarked(1, &HashSet::new());
 
         let page_item = page_list.pages[1].clone();
+        let expected = Style::new().bg(CURRENT_THEME.bookmark_bg).fg(CURRENT_THEME.bookmark_fg);
 
-        assert_eq!(*STYLE_PAGE_BOOKMARKED, page_item.style);
+        assert_eq!(expected, page_item.style);
     }
 
     #[test]
     fn it_keeps_only_one_item_bookmarked_at_a_time() {
         let mut page1 = PagesItem::new(0);
 
-        page1.style = *STYLE_PAGE_BOOKMARKED;
+        page1.style = Style::new().bg(CURRENT_THEME.bookmark_bg).fg(CURRENT_THEME.bookmark_fg);
 
         let mut page_list = PagesList::new(vec![page1, PagesItem::new(1)]);
 
-        page_list.highlight_page_as_bookmarked(1);
+        page_list.highlight_page_as_bookmarked(1, &HashSet::new());
 
         let page_item_with_no_highlight = page_list.pages[0].clone();
         let page_item_highlighet = page_list.pages[1].clone();
+        let expected_bookmarked = Style::new().bg(CURRENT_THEME.bookmark_bg).fg(CURRENT_THEME.bookmark_fg);
+        let expected_reset = Style::new().fg(CURRENT_THEME.text);
+
+        assert_eq!(expected_bookmarked, page_item_highlighet.style);
+        assert_eq!(expected_reset, page_item_with_no_highlight.style);
+    }
+
+    #[test]
+    fn it_computes_row_height_from_the_view_mode() {
+        assert_eq!(1, PageRowView::Compact.height());
+        assert_eq!(4, PageRowView::Detailed.height());
+    }
+
+    #[test]
+    fn it_toggles_between_compact_and_detailed() {
+        assert_eq!(PageRowView::Detailed, PageRowView::Compact.toggle());
+        assert_eq!(PageRowView::Compact, PageRowView::Detailed.toggle());
+    }
+
+    #[test]
+    fn it_cycles_focus_forward_and_wraps_around() {
+        let mut focus = ReaderFocus::default();
+
+        assert_eq!(Focus::PagesList, focus.current());
+
+        focus.next();
+        assert_eq!(Focus::ImageViewport, focus.current());
+
+        focus.next();
+        assert_eq!(Focus::ChapterInfo, focus.current());
+
+        focus.next();
+        assert_eq!(Focus::PagesList, focus.current());
+    }
+
+    #[test]
+    fn it_cycles_focus_backward_and_wraps_around() {
+        let mut focus = ReaderFocus::default();
+
+        focus.previous();
+
+        assert_eq!(Focus::ChapterInfo, focus.current());
+    }
+
+    #[test]
+    fn it_dims_selected_style_when_the_item_is_unfocused() {
+        let mut page = PagesItem::new(0);
+        page.is_focused = false;
+
+        assert_eq!(Style::new().fg(CURRENT_THEME.selected), page.selected_style());
+    }
+
+    #[test]
+    fn it_uses_the_full_selected_style_when_the_item_is_focused() {
+        let page = PagesItem::new(0);
+
+        let expected = Style::new().bg(CURRENT_THEME.selected).fg(CURRENT_THEME.selected_text);
+
+        assert_eq!(expected, page.selected_style());
+    }
+
+    #[test]
+    fn it_promotes_waiting_pages_nearest_the_selection_up_to_the_concurrency_limit() {
+        let mut pages = PagesList::new((0..10).map(PagesItem::new).collect());
+        pages.window = 3;
+        pages.max_concurrent_loads = 2;
+
+        pages.advance_window(5);
+
+        let loading: Vec<usize> =
+            pages.pages.iter().enumerate().filter(|(_, page)| page.state == PageItemState::Loading).map(|(i, _)| i).collect();
+
+        assert_eq!(2, loading.len());
+        assert_eq!(vec![4, 5], loading);
+    }
+
+    #[test]
+    fn it_does_not_exceed_the_concurrency_limit_when_pages_are_already_loading() {
+        let mut pages = PagesList::new((0..10).map(PagesItem::new).collect());
+        pages.max_concurrent_loads = 1;
+        pages.pages[5].mark_loading();
+
+        pages.advance_window(5);
+
+        assert_eq!(1, pages.pages.iter().filter(|page| page.state == PageItemState::Loading).count());
+    }
+
+    #[test]
+    fn it_schedules_an_increasing_backoff_on_repeated_failures() {
+        let mut page = PagesItem::new(0);
+
+        page.mark_failed();
+        assert_eq!(BASE_RETRY_TICKS, page.retry_ticks_remaining);
+
+        page.mark_failed();
+        assert_eq!(BASE_RETRY_TICKS * 2, page.retry_ticks_remaining);
+    }
+
+    #[test]
+    fn it_returns_a_failed_page_to_waiting_once_its_backoff_elapses() {
+        let mut page = PagesItem::new(0);
+        page.mark_failed();
+
+        let ticks = page.retry_ticks_remaining;
+        for _ in 0..ticks {
+            assert_eq!(PageItemState::FailedLoad, page.state);
+            page.on_tick();
+        }
+
+        assert_eq!(PageItemState::Waiting, page.state);
+    }
+
+    #[test]
+    fn it_reports_recovered_pages_via_poll_retries() {
+        let mut pages = PagesList::new(vec![PagesItem::new(0), PagesItem::new(1)]);
+        pages.pages[0].mark_failed();
+
+        let ticks = pages.pages[0].retry_ticks_remaining;
+        for _ in 0..ticks {
+            pages.on_tick();
+        }
+
+        assert_eq!(vec![0], pages.poll_retries());
+    }
+
+    #[test]
+    fn it_resets_the_attempt_count_once_a_page_finishes_loading() {
+        let mut page = PagesItem::new(0);
+        page.mark_failed();
+        page.mark_finished();
+
+        assert_eq!(0, page.attempt);
+        assert_eq!(PageItemState::FinishedLoad, page.state);
+    }
+
+    #[test]
+    fn it_tracks_read_pages_separately_from_the_bookmark() {
+        let mut state = PagesListState::default();
+
+        state.mark_read(0);
+        state.mark_read(2);
+        state.set_page_bookmarked(2);
+
+        assert_eq!(2, state.read_pages.len());
+        assert_eq!(Some(2), state.page_bookmarked);
+    }
+
+    #[test]
+    fn it_reports_aggregate_read_progress() {
+        let mut state = PagesListState::default();
+
+        state.mark_read(0);
+        state.mark_read(1);
+
+        assert_eq!(ReadProgress { read: 2, total: 5 }, state.progress(5));
+    }
+
+    #[test]
+    fn it_styles_read_pages_with_the_subtle_read_color_instead_of_the_bookmark_color() {
+        let mut page_list = PagesList::new(vec![PagesItem::new(0), PagesItem::new(1)]);
+        let read_pages = HashSet::from([0]);
+
+        page_list.highlight_page_as_bookmarked(1, &read_pages);
 
-        assert_eq!(*STYLE_PAGE_BOOKMARKED, page_item_highlighet.style);
-        assert_eq!(Style::default(), page_item_with_no_highlight.style);
+        assert_eq!(Style::new().fg(CURRENT_THEME.read), page_list.pages[0].style);
+        assert_eq!(
+            Style::new().bg(CURRENT_THEME.bookmark_bg).fg(CURRENT_THEME.bookmark_fg),
+            page_list.pages[1].style
+        );
     }
 }